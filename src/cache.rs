@@ -0,0 +1,213 @@
+//! On-disk caching of fetched asset bodies, keyed by a hash of the base URL,
+//! asset path and query parameters, with a small sidecar recording the
+//! validators needed for conditional GETs (`ETag` / `Last-Modified`).
+
+use std::hash::{Hash, Hasher};
+
+/// The validators returned alongside a cached body, used to revalidate it
+/// with the server via `If-None-Match`/`If-Modified-Since`.
+#[derive(Default)]
+pub(crate) struct CacheMeta {
+    pub(crate) etag: Option<String>,
+    pub(crate) last_modified: Option<String>,
+}
+
+impl CacheMeta {
+    pub(crate) fn encode(&self) -> String {
+        let mut out = String::new();
+        if let Some(etag) = &self.etag {
+            out.push_str("etag: ");
+            out.push_str(etag);
+            out.push('\n');
+        }
+        if let Some(last_modified) = &self.last_modified {
+            out.push_str("last-modified: ");
+            out.push_str(last_modified);
+            out.push('\n');
+        }
+        out
+    }
+
+    pub(crate) fn decode(data: &str) -> Self {
+        let mut meta = Self::default();
+        for line in data.lines() {
+            if let Some(value) = line.strip_prefix("etag: ") {
+                meta.etag = Some(value.to_string());
+            } else if let Some(value) = line.strip_prefix("last-modified: ") {
+                meta.last_modified = Some(value.to_string());
+            }
+        }
+        meta
+    }
+}
+
+/// Computes a stable cache key for a request, used to name the cached body
+/// and its sidecar metadata. Hashes the base URL and query parameters in
+/// addition to the asset path, so two `HttpAssetReader`s sharing a
+/// `cache_dir` (e.g. different hosts, or the same host with different
+/// per-tenant query parameters) never collide on the same relative path.
+pub(crate) fn cache_key(base_url: &str, path: &str, query_params: &[(String, String)]) -> String {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    base_url.hash(&mut hasher);
+    path.hash(&mut hasher);
+    query_params.hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+fn meta_file_name(key: &str) -> String {
+    format!("{key}.cache-meta")
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+mod native {
+    use super::{meta_file_name, CacheMeta};
+    use std::path::Path;
+
+    pub(crate) fn read(dir: &Path, key: &str) -> Option<(Vec<u8>, CacheMeta)> {
+        let bytes = std::fs::read(dir.join(key)).ok()?;
+        let meta = std::fs::read_to_string(dir.join(meta_file_name(key)))
+            .map(|data| CacheMeta::decode(&data))
+            .unwrap_or_default();
+        Some((bytes, meta))
+    }
+
+    pub(crate) fn write(dir: &Path, key: &str, bytes: &[u8], meta: &CacheMeta) {
+        if std::fs::create_dir_all(dir).is_err() {
+            return;
+        }
+        if std::fs::write(dir.join(key), bytes).is_err() {
+            return;
+        }
+        let _ = std::fs::write(dir.join(meta_file_name(key)), meta.encode());
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) use native::{read, write};
+
+#[cfg(target_arch = "wasm32")]
+mod opfs {
+    use super::{meta_file_name, CacheMeta};
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_futures::JsFuture;
+    use web_sys::{FileSystemDirectoryHandle, FileSystemGetFileOptions};
+
+    async fn dir_handle(dir_name: &str, create: bool) -> Option<FileSystemDirectoryHandle> {
+        let root: FileSystemDirectoryHandle =
+            JsFuture::from(web_sys::window()?.navigator().storage().get_directory())
+                .await
+                .ok()?
+                .dyn_into()
+                .ok()?;
+        let mut opts = web_sys::FileSystemGetDirectoryOptions::new();
+        opts.set_create(create);
+        JsFuture::from(root.get_directory_handle_with_options(dir_name, &opts))
+            .await
+            .ok()?
+            .dyn_into()
+            .ok()
+    }
+
+    async fn read_file(dir: &FileSystemDirectoryHandle, name: &str) -> Option<Vec<u8>> {
+        let handle: web_sys::FileSystemFileHandle =
+            JsFuture::from(dir.get_file_handle(name)).await.ok()?.dyn_into().ok()?;
+        let file = JsFuture::from(handle.get_file()).await.ok()?;
+        let file: web_sys::File = file.dyn_into().ok()?;
+        let buffer = JsFuture::from(file.array_buffer()).await.ok()?;
+        Some(js_sys::Uint8Array::new(&buffer).to_vec())
+    }
+
+    async fn write_file(dir: &FileSystemDirectoryHandle, name: &str, bytes: &[u8]) -> Option<()> {
+        let mut opts = FileSystemGetFileOptions::new();
+        opts.set_create(true);
+        let handle: web_sys::FileSystemFileHandle = JsFuture::from(
+            dir.get_file_handle_with_options(name, &opts),
+        )
+        .await
+        .ok()?
+        .dyn_into()
+        .ok()?;
+        let writable = JsFuture::from(handle.create_writable()).await.ok()?;
+        let writable: web_sys::FileSystemWritableFileStream = writable.dyn_into().ok()?;
+        let array = js_sys::Uint8Array::from(bytes);
+        JsFuture::from(writable.write_with_buffer_source(&array).ok()?)
+            .await
+            .ok()?;
+        JsFuture::from(writable.close()).await.ok()?;
+        Some(())
+    }
+
+    pub(crate) async fn read(dir_name: &str, key: &str) -> Option<(Vec<u8>, CacheMeta)> {
+        let dir = dir_handle(dir_name, false).await?;
+        let bytes = read_file(&dir, key).await?;
+        let meta = read_file(&dir, &meta_file_name(key))
+            .await
+            .map(|data| CacheMeta::decode(&String::from_utf8_lossy(&data)))
+            .unwrap_or_default();
+        Some((bytes, meta))
+    }
+
+    pub(crate) async fn write(dir_name: &str, key: &str, bytes: &[u8], meta: &CacheMeta) {
+        let Some(dir) = dir_handle(dir_name, true).await else {
+            return;
+        };
+        if write_file(&dir, key, bytes).await.is_none() {
+            return;
+        }
+        write_file(&dir, &meta_file_name(key), meta.encode().as_bytes()).await;
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) use opfs::{read, write};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cache_key_is_stable() {
+        let params = vec![("a".to_string(), "1".to_string())];
+        assert_eq!(
+            cache_key("https://example.com", "foo.png", &params),
+            cache_key("https://example.com", "foo.png", &params)
+        );
+    }
+
+    #[test]
+    fn cache_key_differs_by_base_url() {
+        let params: Vec<(String, String)> = Vec::new();
+        assert_ne!(
+            cache_key("https://a.example.com", "foo.png", &params),
+            cache_key("https://b.example.com", "foo.png", &params)
+        );
+    }
+
+    #[test]
+    fn cache_key_differs_by_query_params() {
+        let no_params: Vec<(String, String)> = Vec::new();
+        let with_params = vec![("api_key".to_string(), "tenant-a".to_string())];
+        assert_ne!(
+            cache_key("https://example.com", "foo.png", &no_params),
+            cache_key("https://example.com", "foo.png", &with_params)
+        );
+    }
+
+    #[test]
+    fn cache_meta_round_trips_through_encode_decode() {
+        let meta = CacheMeta {
+            etag: Some("\"abc123\"".to_string()),
+            last_modified: Some("Wed, 21 Oct 2015 07:28:00 GMT".to_string()),
+        };
+        let decoded = CacheMeta::decode(&meta.encode());
+        assert_eq!(decoded.etag, meta.etag);
+        assert_eq!(decoded.last_modified, meta.last_modified);
+    }
+
+    #[test]
+    fn cache_meta_decode_handles_missing_fields() {
+        let decoded = CacheMeta::decode("");
+        assert_eq!(decoded.etag, None);
+        assert_eq!(decoded.last_modified, None);
+    }
+}
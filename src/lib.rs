@@ -10,60 +10,501 @@ use std::path::{Path, PathBuf};
 use std::pin::Pin;
 use std::task::Poll;
 
+mod cache;
+use cache::CacheMeta;
+
+/// Static, per-source request configuration applied to every request made by a
+/// [`HttpAssetReader`]: extra headers, an optional bearer token and user-agent
+/// override, query parameters appended to the request URL, an optional
+/// on-disk cache, and the timeout/retry policy.
+#[derive(Clone)]
+pub struct HttpAssetReaderConfig {
+    /// Header name/value pairs sent with every request, e.g. for API keys.
+    pub headers: Vec<(String, String)>,
+    /// If set, sent as an `Authorization: Bearer <token>` header.
+    pub bearer_token: Option<String>,
+    /// If set, overrides the `User-Agent` header sent with every request.
+    pub user_agent: Option<String>,
+    /// Query parameters appended to every request, e.g. `("api_key", "...")`.
+    pub query_params: Vec<(String, String)>,
+    /// If set, fetched bodies are cached here, keyed by a hash of the full URL,
+    /// and revalidated with `If-None-Match`/`If-Modified-Since` on subsequent
+    /// reads. On wasm32 this names a directory inside the Origin Private File
+    /// System rather than a real filesystem path. Responses sent with
+    /// `Cache-Control: no-store` are never cached.
+    pub cache_dir: Option<PathBuf>,
+    /// If set, this extension (without the leading dot, e.g. `"jpg"`) is
+    /// stripped from the end of the incoming path before it is sent as a
+    /// request, while still being visible to Bevy so it can pick the right
+    /// `AssetLoader`. Lets extension-less endpoints (e.g. a REST API
+    /// returning an image) be loaded as `"api/avatar/42.jpg"`.
+    pub fake_extension: Option<String>,
+    /// Per-request timeout. Defaults to 5 seconds.
+    pub timeout: std::time::Duration,
+    /// How many times to retry a request that fails with a connection error
+    /// or a retryable status code (`408`, `429`, `500`, `502`, `503`, `504`)
+    /// before giving up. Defaults to 3.
+    pub max_retries: u32,
+    /// The base duration retries are backed off by, doubled on every attempt
+    /// unless the response carries a `Retry-After` header given in whole
+    /// seconds. The HTTP-date form of `Retry-After` is not parsed and falls
+    /// back to the doubled backoff. Defaults to 200ms.
+    pub base_backoff: std::time::Duration,
+}
+
+impl Default for HttpAssetReaderConfig {
+    fn default() -> Self {
+        Self {
+            headers: Vec::new(),
+            bearer_token: None,
+            user_agent: None,
+            query_params: Vec::new(),
+            cache_dir: None,
+            fake_extension: None,
+            timeout: std::time::Duration::from_secs(5),
+            max_retries: 3,
+            base_backoff: std::time::Duration::from_millis(200),
+        }
+    }
+}
+
 /// A custom asset reader implementation that wraps a given asset reader implementation
 pub struct HttpAssetReader {
+    #[cfg(not(target_arch = "wasm32"))]
     client: surf::Client,
-    /// A random sequence that is interpreted as a slash. Used to work around
-    /// the fact that bevy treats slashes as directories and will subsequently
-    /// try to load sub-entities from the directory.
-    fake_slash: String,
+    /// The base URL requests are resolved against. On wasm32 this is also
+    /// what requests are built from, since there is no `surf::Client` to
+    /// hold it for us; on both targets it's folded into the cache key so
+    /// that two readers sharing a `cache_dir` don't collide on identical
+    /// relative paths.
+    base_url: String,
+    config: HttpAssetReaderConfig,
 }
 
 impl HttpAssetReader {
     /// Creates a new `HttpAssetReader`. The path provided will be used to build URLs to query for assets.
-    pub fn new(base_url: &str, fake_slash: String) -> Self {
-        let base_url = surf::Url::parse(base_url).expect("invalid base url");
+    pub fn new(base_url: &str) -> Self {
+        Self::with_config(base_url, HttpAssetReaderConfig::default())
+    }
+
+    /// Creates a new `HttpAssetReader` with custom headers, auth and query parameters
+    /// applied to every request. See [`HttpAssetReaderConfig`].
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn with_config(base_url: &str, config: HttpAssetReaderConfig) -> Self {
+        let url = surf::Url::parse(base_url).expect("invalid base url");
+
+        let client = surf::Config::new().set_timeout(Some(config.timeout));
+        let client = client.set_base_url(url);
 
-        let client = surf::Config::new().set_timeout(Some(std::time::Duration::from_secs(5)));
-        let client = client.set_base_url(base_url);
+        let client: surf::Client = client.try_into().expect("could not create http client");
+        let client = client.with(surf::middleware::Redirect::default());
 
-        let client = client.try_into().expect("could not create http client");
+        Self {
+            client,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            config,
+        }
+    }
+
+    /// Creates a new `HttpAssetReader` with custom headers, auth and query parameters
+    /// applied to every request. See [`HttpAssetReaderConfig`].
+    #[cfg(target_arch = "wasm32")]
+    pub fn with_config(base_url: &str, config: HttpAssetReaderConfig) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            config,
+        }
+    }
 
-        Self { client, fake_slash }
+    /// Strips the configured [`HttpAssetReaderConfig::fake_extension`] suffix
+    /// from `path`, if present, so it isn't sent as part of the request.
+    /// Bevy still sees the original path (with the fake extension) when
+    /// picking an `AssetLoader`.
+    fn strip_fake_extension(&self, path: &str) -> String {
+        match &self.config.fake_extension {
+            Some(ext) if path.ends_with(&format!(".{ext}")) => {
+                path[..path.len() - ext.len() - 1].to_string()
+            }
+            _ => path.to_string(),
+        }
     }
 
+    /// Retryable status codes: transient errors worth retrying with backoff.
+    const RETRYABLE_STATUS_CODES: [u16; 6] = [408, 429, 500, 502, 503, 504];
+
+    /// Extensions of the asset kinds this crate is commonly used to load,
+    /// used by `is_directory` to tell a file path from a directory path
+    /// without needing a network round-trip. Not exhaustive; a configured
+    /// `fake_extension` is always recognized in addition to this list.
+    const RECOGNIZED_ASSET_EXTENSIONS: [&str; 29] = [
+        "png", "jpg", "jpeg", "gif", "bmp", "tga", "webp", "ico", "tiff", "ktx", "ktx2", "astc",
+        "dds", "basis", "hdr", "exr", "gltf", "glb", "obj", "fbx", "ogg", "mp3", "wav", "ttf",
+        "otf", "ron", "json", "wgsl", "meta",
+    ];
+
+    /// Computes the backoff for a given retry attempt, doubling `base` each
+    /// time. Clamped to a 20-attempt shift and `saturating_mul` so that an
+    /// unusually large `max_retries` can't overflow or panic.
+    fn backoff_for(base: std::time::Duration, attempt: u32) -> std::time::Duration {
+        base.saturating_mul(1u32 << attempt.min(20))
+    }
+
+    /// Sleeps for `duration`. Redirects are followed by the client's surf
+    /// `Redirect` middleware, so this only backs the retry loop's backoff.
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn sleep(duration: std::time::Duration) {
+        async_io::Timer::after(duration).await;
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
     async fn fetch_bytes<'a>(&self, path: &str) -> Result<Box<Reader<'a>>, AssetReaderError> {
-        let resp = self.client.get(path).await;
+        let cache_key = self
+            .config
+            .cache_dir
+            .is_some()
+            .then(|| cache::cache_key(&self.base_url, path, &self.config.query_params));
+        let cached = match (&self.config.cache_dir, &cache_key) {
+            (Some(dir), Some(key)) => cache::read(dir, key),
+            _ => None,
+        };
 
-        trace!("fetched {resp:?} ... ");
-        let mut resp = resp.map_err(|e| {
-            AssetReaderError::Io(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!("error fetching {path}: {e}"),
-            ))
-        })?;
+        let max_attempts = self.config.max_retries + 1;
+        for attempt in 0..max_attempts {
+            let mut req = self.client.get(path);
+
+            for (name, value) in &self.config.headers {
+                req = req.header(name.as_str(), value.as_str());
+            }
+            if let Some(user_agent) = &self.config.user_agent {
+                req = req.header("User-Agent", user_agent.as_str());
+            }
+            if let Some(token) = &self.config.bearer_token {
+                req = req.header("Authorization", format!("Bearer {token}"));
+            }
+            if !self.config.query_params.is_empty() {
+                req = req.query(&self.config.query_params).map_err(|e| {
+                    AssetReaderError::Io(std::io::Error::new(
+                        std::io::ErrorKind::Other,
+                        format!("error building query for {path}: {e}"),
+                    ))
+                })?;
+            }
+            if let Some((_, meta)) = &cached {
+                if let Some(etag) = &meta.etag {
+                    req = req.header("If-None-Match", etag.as_str());
+                }
+                if let Some(last_modified) = &meta.last_modified {
+                    req = req.header("If-Modified-Since", last_modified.as_str());
+                }
+            }
 
-        let status = resp.status();
+            let resp = req.await;
+            trace!("fetched {resp:?} ... ");
 
-        if !status.is_success() {
-            let err = match status {
-                surf::StatusCode::NotFound => AssetReaderError::NotFound(path.into()),
-                _ => AssetReaderError::Io(std::io::Error::new(
+            let mut resp = match resp {
+                Ok(resp) => resp,
+                Err(e) => {
+                    if attempt + 1 < max_attempts {
+                        Self::sleep(Self::backoff_for(self.config.base_backoff, attempt)).await;
+                        continue;
+                    }
+                    return Err(AssetReaderError::Io(std::io::Error::new(
+                        std::io::ErrorKind::Other,
+                        format!("error fetching {path} after {} attempt(s): {e}", attempt + 1),
+                    )));
+                }
+            };
+
+            let status = resp.status();
+
+            if status == surf::StatusCode::NotModified {
+                if let Some((bytes, _)) = cached {
+                    return Ok(Box::new(bevy::asset::io::VecReader::new(bytes)));
+                }
+            }
+
+            if status == surf::StatusCode::NotFound {
+                return Err(AssetReaderError::NotFound(path.into()));
+            }
+
+            if !status.is_success() {
+                if Self::RETRYABLE_STATUS_CODES.contains(&(status as u16))
+                    && attempt + 1 < max_attempts
+                {
+                    let retry_after = resp
+                        .header("Retry-After")
+                        .and_then(|v| v.as_str().parse::<u64>().ok())
+                        .map(std::time::Duration::from_secs);
+                    Self::sleep(retry_after.unwrap_or(Self::backoff_for(self.config.base_backoff, attempt)))
+                        .await;
+                    continue;
+                }
+                return Err(AssetReaderError::Io(std::io::Error::new(
                     std::io::ErrorKind::Other,
-                    format!("bad status code: {status}"),
-                )),
+                    format!(
+                        "bad status code {status} for {path} after {} attempt(s)",
+                        attempt + 1
+                    ),
+                )));
             };
-            return Err(err);
-        };
 
-        let bytes = resp.body_bytes().await.map_err(|e| {
+            let bytes = resp.body_bytes().await.map_err(|e| {
+                AssetReaderError::Io(std::io::Error::new(
+                    std::io::ErrorKind::Other,
+                    format!("error getting bytes for {path}: {e}"),
+                ))
+            })?;
+
+            if let (Some(dir), Some(key)) = (&self.config.cache_dir, &cache_key) {
+                let no_store = resp
+                    .header("Cache-Control")
+                    .map(|v| v.as_str().contains("no-store"))
+                    .unwrap_or(false);
+                if !no_store {
+                    let meta = CacheMeta {
+                        etag: resp.header("ETag").map(|v| v.as_str().to_string()),
+                        last_modified: resp.header("Last-Modified").map(|v| v.as_str().to_string()),
+                    };
+                    cache::write(dir, key, &bytes, &meta);
+                }
+            }
+
+            return Ok(Box::new(bevy::asset::io::VecReader::new(bytes)));
+        }
+
+        unreachable!("the loop above always returns before exhausting its attempts")
+    }
+
+    /// Percent-encodes a query string component. The browser `fetch` API has no
+    /// notion of a query builder, so we have to assemble the URL ourselves.
+    #[cfg(target_arch = "wasm32")]
+    fn percent_encode(value: &str) -> String {
+        let mut out = String::with_capacity(value.len());
+        for byte in value.bytes() {
+            match byte {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                    out.push(byte as char);
+                }
+                _ => out.push_str(&format!("%{byte:02X}")),
+            }
+        }
+        out
+    }
+
+    /// Sleeps for `duration` using the browser's `setTimeout`.
+    #[cfg(target_arch = "wasm32")]
+    async fn sleep(duration: std::time::Duration) {
+        use wasm_bindgen_futures::JsFuture;
+
+        let window = web_sys::window().expect("HttpAssetReader requires a browser window");
+        let promise = js_sys::Promise::new(&mut |resolve, _reject| {
+            let _ = window.set_timeout_with_callback_and_timeout_and_arguments_0(
+                &resolve,
+                duration.as_millis() as i32,
+            );
+        });
+        let _ = JsFuture::from(promise).await;
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    async fn fetch_bytes<'a>(&self, path: &str) -> Result<Box<Reader<'a>>, AssetReaderError> {
+        use wasm_bindgen::JsCast;
+        use wasm_bindgen_futures::JsFuture;
+        use web_sys::{AbortSignal, Request, RequestInit, RequestMode, Response};
+
+        let io_err = |context: &str, e: wasm_bindgen::JsValue| {
             AssetReaderError::Io(std::io::Error::new(
                 std::io::ErrorKind::Other,
-                format!("error getting bytes for {path}: {e}"),
+                format!("{context} {path}: {e:?}"),
             ))
-        })?;
-        let reader = bevy::asset::io::VecReader::new(bytes);
-        Ok(Box::new(reader))
+        };
+
+        let cache_dir_name = self.config.cache_dir.as_ref().map(|d| d.display().to_string());
+        let cache_key = cache_dir_name
+            .as_ref()
+            .map(|_| cache::cache_key(&self.base_url, path, &self.config.query_params));
+        let cached = match (&cache_dir_name, &cache_key) {
+            (Some(dir), Some(key)) => cache::read(dir, key).await,
+            _ => None,
+        };
+
+        let mut url = format!("{}/{}", self.base_url, path.trim_start_matches('/'));
+        if !self.config.query_params.is_empty() {
+            let query = self
+                .config
+                .query_params
+                .iter()
+                .map(|(k, v)| format!("{}={}", Self::percent_encode(k), Self::percent_encode(v)))
+                .collect::<Vec<_>>()
+                .join("&");
+            url = format!("{url}?{query}");
+        }
+
+        let max_attempts = self.config.max_retries + 1;
+        for attempt in 0..max_attempts {
+            let opts = RequestInit::new();
+            opts.set_method("GET");
+            opts.set_mode(RequestMode::Cors);
+
+            // `AbortSignal::timeout` aborts the fetch on its own after the
+            // given delay, so there's no timer/closure for us to leak or
+            // clean up on the (common) success path.
+            let signal = AbortSignal::timeout(self.config.timeout.as_millis() as u32);
+            opts.set_signal(Some(&signal));
+
+            let request = Request::new_with_str_and_init(&url, &opts)
+                .map_err(|e| io_err("error building request for", e))?;
+
+            let headers = request.headers();
+            for (name, value) in &self.config.headers {
+                headers
+                    .set(name, value)
+                    .map_err(|e| io_err("error setting header for", e))?;
+            }
+            if let Some(token) = &self.config.bearer_token {
+                headers
+                    .set("Authorization", &format!("Bearer {token}"))
+                    .map_err(|e| io_err("error setting header for", e))?;
+            }
+            if let Some((_, meta)) = &cached {
+                if let Some(etag) = &meta.etag {
+                    headers
+                        .set("If-None-Match", etag)
+                        .map_err(|e| io_err("error setting header for", e))?;
+                }
+                if let Some(last_modified) = &meta.last_modified {
+                    headers
+                        .set("If-Modified-Since", last_modified)
+                        .map_err(|e| io_err("error setting header for", e))?;
+                }
+            }
+            // Note: the browser `fetch` API does not allow overriding
+            // `User-Agent`, so `config.user_agent` is ignored on wasm32.
+
+            let window = web_sys::window().expect("HttpAssetReader requires a browser window");
+            let fetch_result = JsFuture::from(window.fetch_with_request(&request)).await;
+
+            let resp_value = match fetch_result {
+                Ok(v) => v,
+                Err(e) => {
+                    if attempt + 1 < max_attempts {
+                        Self::sleep(Self::backoff_for(self.config.base_backoff, attempt)).await;
+                        continue;
+                    }
+                    return Err(io_err(
+                        &format!("error fetching (after {} attempt(s)) ", attempt + 1),
+                        e,
+                    ));
+                }
+            };
+            let resp: Response = resp_value
+                .dyn_into()
+                .expect("fetch always resolves to a Response");
+
+            if resp.status() == 304 {
+                if let Some((bytes, _)) = cached {
+                    return Ok(Box::new(bevy::asset::io::VecReader::new(bytes)));
+                }
+            }
+
+            if resp.status() == 404 {
+                return Err(AssetReaderError::NotFound(path.into()));
+            }
+
+            if !resp.ok() {
+                if Self::RETRYABLE_STATUS_CODES.contains(&resp.status())
+                    && attempt + 1 < max_attempts
+                {
+                    let retry_after = resp
+                        .headers()
+                        .get("Retry-After")
+                        .ok()
+                        .flatten()
+                        .and_then(|v| v.parse::<u64>().ok())
+                        .map(std::time::Duration::from_secs);
+                    Self::sleep(retry_after.unwrap_or(Self::backoff_for(self.config.base_backoff, attempt)))
+                        .await;
+                    continue;
+                }
+                return Err(AssetReaderError::Io(std::io::Error::new(
+                    std::io::ErrorKind::Other,
+                    format!(
+                        "bad status code {} for {path} after {} attempt(s)",
+                        resp.status(),
+                        attempt + 1
+                    ),
+                )));
+            }
+
+            let buffer = JsFuture::from(
+                resp.array_buffer()
+                    .map_err(|e| io_err("error reading body for", e))?,
+            )
+            .await
+            .map_err(|e| io_err("error reading body for", e))?;
+            let bytes = js_sys::Uint8Array::new(&buffer).to_vec();
+
+            if let (Some(dir), Some(key)) = (&cache_dir_name, &cache_key) {
+                let no_store = resp
+                    .headers()
+                    .get("Cache-Control")
+                    .ok()
+                    .flatten()
+                    .map(|v| v.contains("no-store"))
+                    .unwrap_or(false);
+                if !no_store {
+                    let meta = CacheMeta {
+                        etag: resp.headers().get("ETag").ok().flatten(),
+                        last_modified: resp.headers().get("Last-Modified").ok().flatten(),
+                    };
+                    cache::write(dir, key, &bytes, &meta).await;
+                }
+            }
+
+            return Ok(Box::new(bevy::asset::io::VecReader::new(bytes)));
+        }
+
+        unreachable!("the loop above always returns before exhausting its attempts")
+    }
+
+    /// Fetches and parses `<path>/.index.json`, a server-published manifest
+    /// listing the entries of a directory, returning `None` if no such
+    /// manifest exists or it could not be parsed. Entries are returned
+    /// joined onto `path`, since `AssetReader::read_directory` contracts
+    /// its stream items to be paths relative to the asset-source root.
+    async fn fetch_manifest(&self, path: &str) -> Option<Vec<PathBuf>> {
+        let dir = path.trim_end_matches('/');
+        let manifest_path = format!("{dir}/.index.json");
+        let mut reader = self.fetch_bytes(&manifest_path).await.ok()?;
+        let mut bytes = Vec::new();
+        futures_lite::AsyncReadExt::read_to_end(&mut reader, &mut bytes)
+            .await
+            .ok()?;
+        let entries: Vec<String> = serde_json::from_slice(&bytes).ok()?;
+        Some(
+            entries
+                .into_iter()
+                .filter_map(|entry| {
+                    // The manifest is server-controlled content we consume
+                    // blindly; reject anything that isn't a plain relative
+                    // path so a malicious or misconfigured server can't make
+                    // `Path::join` escape `dir` (e.g. an absolute entry like
+                    // `/etc/passwd` discards `dir` entirely) or walk out of
+                    // it via `..`.
+                    let entry_path = Path::new(&entry);
+                    let is_safe = entry_path.is_relative()
+                        && !entry_path
+                            .components()
+                            .any(|c| matches!(c, std::path::Component::ParentDir));
+                    if is_safe {
+                        Some(Path::new(dir).join(entry_path))
+                    } else {
+                        warn!("ignoring unsafe manifest entry {entry:?} for directory {dir}");
+                        None
+                    }
+                })
+                .collect(),
+        )
     }
 }
 
@@ -80,12 +521,28 @@ impl futures_core::Stream for EmptyPathStream {
     }
 }
 
+/// A [`PathStream`] over the entries listed in a directory manifest.
+struct ManifestPathStream {
+    paths: std::vec::IntoIter<PathBuf>,
+}
+
+impl futures_core::Stream for ManifestPathStream {
+    type Item = PathBuf;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        _cx: &mut std::task::Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        Poll::Ready(self.paths.next())
+    }
+}
+
 impl AssetReader for HttpAssetReader {
     fn read<'a>(
         &'a self,
         path: &'a Path,
     ) -> BoxedFuture<'a, Result<Box<Reader<'a>>, AssetReaderError>> {
-        let path = path.display().to_string().replace(&self.fake_slash, "/");
+        let path = self.strip_fake_extension(&path.display().to_string());
         Box::pin(async move { self.fetch_bytes(&path).await })
     }
 
@@ -93,8 +550,8 @@ impl AssetReader for HttpAssetReader {
         &'a self,
         path: &'a Path,
     ) -> BoxedFuture<'a, Result<Box<Reader<'a>>, AssetReaderError>> {
+        let path = self.strip_fake_extension(&path.display().to_string());
         Box::pin(async move {
-            let path = path.display().to_string().replace(&self.fake_slash, "/");
             let meta_path = path + ".meta";
             Ok(self.fetch_bytes(&meta_path).await?)
         })
@@ -102,19 +559,48 @@ impl AssetReader for HttpAssetReader {
 
     fn read_directory<'a>(
         &'a self,
-        _path: &'a Path,
+        path: &'a Path,
     ) -> BoxedFuture<'a, Result<Box<PathStream>, AssetReaderError>> {
-        let stream: Box<PathStream> = Box::new(EmptyPathStream);
-        error!("Reading directories is not supported with the HttpAssetReader");
-        Box::pin(async move { Ok(stream) })
+        let path = path.display().to_string();
+        Box::pin(async move {
+            if let Some(paths) = self.fetch_manifest(&path).await {
+                let stream: Box<PathStream> = Box::new(ManifestPathStream {
+                    paths: paths.into_iter(),
+                });
+                return Ok(stream);
+            }
+            debug!("No `.index.json` manifest found for directory {path}; returning an empty listing");
+            let stream: Box<PathStream> = Box::new(EmptyPathStream);
+            Ok(stream)
+        })
     }
 
     fn is_directory<'a>(
         &'a self,
-        _path: &'a Path,
+        path: &'a Path,
     ) -> BoxedFuture<'a, std::result::Result<bool, AssetReaderError>> {
-        error!("Reading directories is not supported with the HttpAssetReader");
-        Box::pin(async move { Ok(false) })
+        // Paths ending in a recognized asset extension are assets, never
+        // directories; skip the manifest round-trip Bevy would otherwise
+        // trigger while probing for sub-assets. Unlike a bare `.extension()`
+        // check, this doesn't misclassify directories whose name happens to
+        // contain a dot (e.g. `assets/v1.2`).
+        let is_recognized_extension = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| {
+                let ext = ext.to_ascii_lowercase();
+                Self::RECOGNIZED_ASSET_EXTENSIONS.contains(&ext.as_str())
+                    || self
+                        .config
+                        .fake_extension
+                        .as_deref()
+                        .is_some_and(|fake| fake.eq_ignore_ascii_case(&ext))
+            });
+        if is_recognized_extension {
+            return Box::pin(async { Ok(false) });
+        }
+        let path = path.display().to_string();
+        Box::pin(async move { Ok(self.fetch_manifest(&path).await.is_some()) })
     }
 }
 
@@ -122,21 +608,75 @@ impl AssetReader for HttpAssetReader {
 pub struct HttpAssetReaderPlugin {
     pub id: String,
     pub base_url: String,
-    /// A random sequence that is interpreted as a slash. Used to work around
-    /// the fact that bevy treats slashes as directories and will subsequently
-    /// try to load sub-entities from the directory.
-    pub fake_slash: String,
+    /// Headers, auth, query parameters, caching and the fake extension
+    /// applied to every outgoing request. Defaults to no customization.
+    pub config: HttpAssetReaderConfig,
 }
 
 impl Plugin for HttpAssetReaderPlugin {
     fn build(&self, app: &mut App) {
         let id = self.id.clone();
         let base_url = self.base_url.clone();
-        let fake_slash = self.fake_slash.clone();
+        let config = self.config.clone();
         app.register_asset_source(
             AssetSourceId::Name(id.into()),
-            AssetSource::build()
-                .with_reader(move || Box::new(HttpAssetReader::new(&base_url, fake_slash.clone()))),
+            AssetSource::build().with_reader(move || {
+                Box::new(HttpAssetReader::with_config(&base_url, config.clone()))
+            }),
         );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_for_doubles_each_attempt() {
+        let base = std::time::Duration::from_millis(200);
+        assert_eq!(HttpAssetReader::backoff_for(base, 0), base);
+        assert_eq!(HttpAssetReader::backoff_for(base, 1), base * 2);
+        assert_eq!(HttpAssetReader::backoff_for(base, 2), base * 4);
+    }
+
+    #[test]
+    fn backoff_for_saturates_instead_of_overflowing() {
+        let base = std::time::Duration::from_millis(200);
+        // A huge attempt count must not overflow or panic; it clamps to the
+        // shift at attempt 20.
+        assert_eq!(
+            HttpAssetReader::backoff_for(base, u32::MAX),
+            HttpAssetReader::backoff_for(base, 20)
+        );
+    }
+
+    #[test]
+    fn strip_fake_extension_strips_configured_suffix() {
+        let reader = HttpAssetReader::with_config(
+            "https://example.com",
+            HttpAssetReaderConfig {
+                fake_extension: Some("jpg".to_string()),
+                ..Default::default()
+            },
+        );
+        assert_eq!(
+            reader.strip_fake_extension("api/avatar/42.jpg"),
+            "api/avatar/42"
+        );
+    }
+
+    #[test]
+    fn strip_fake_extension_leaves_other_paths_untouched() {
+        let reader = HttpAssetReader::with_config(
+            "https://example.com",
+            HttpAssetReaderConfig {
+                fake_extension: Some("jpg".to_string()),
+                ..Default::default()
+            },
+        );
+        assert_eq!(reader.strip_fake_extension("textures/grass.png"), "textures/grass.png");
+
+        let reader = HttpAssetReader::new("https://example.com");
+        assert_eq!(reader.strip_fake_extension("api/avatar/42.jpg"), "api/avatar/42.jpg");
+    }
+}